@@ -0,0 +1,118 @@
+use std::path::Path;
+use std::process::{Child, Command};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tauri::State;
+
+use crate::config::AppConfig;
+
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Tracks the spawned Python backend process so it can be stopped/restarted
+/// from the frontend. `kill` is also called from the app's `RunEvent::Exit`
+/// handler in `main`, since a desktop Tauri app calls `std::process::exit`
+/// when the last window closes — that skips `Drop` entirely, so reaping the
+/// child can't be left to the destructor.
+#[derive(Default)]
+pub struct BackendSupervisor(Mutex<Option<Child>>);
+
+impl BackendSupervisor {
+    fn replace(&self, child: Child) {
+        let mut guard = self.0.lock().unwrap();
+        if let Some(old) = guard.replace(child) {
+            kill_and_reap(old);
+        }
+    }
+
+    pub fn kill(&self) {
+        if let Some(child) = self.0.lock().unwrap().take() {
+            kill_and_reap(child);
+        }
+    }
+}
+
+/// `Child::kill` only sends the signal; without `wait`-ing afterwards the
+/// process stays a zombie until the whole app quits.
+fn kill_and_reap(mut child: Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+fn repo_root() -> Result<std::path::PathBuf, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::canonicalize(root).map_err(|e| e.to_string())
+}
+
+fn spawn_backend(config: &AppConfig) -> Result<Child, String> {
+    let root = repo_root()?;
+
+    let venv_python = root.join("venv/bin/python3");
+    let interpreter = if venv_python.exists() {
+        venv_python
+    } else {
+        Path::new("python3").to_path_buf()
+    };
+
+    Command::new(&interpreter)
+        .current_dir(&root)
+        .args(["src/main.py", "web", "--port"])
+        .arg(config.backend_port.to_string())
+        .spawn()
+        .map_err(|e| format!("Failed to launch backend: {e}"))
+}
+
+/// Blocks until the backend's health endpoint responds successfully, so
+/// `start_backend`/`restart_backend` only resolve once something is
+/// actually listening instead of racing the frontend against a process
+/// that's still importing modules.
+async fn wait_until_ready(config: &AppConfig) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(READY_POLL_INTERVAL)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let health_url = config.health_url();
+    let deadline = std::time::Instant::now() + READY_TIMEOUT;
+
+    while std::time::Instant::now() < deadline {
+        if let Ok(response) = client.get(&health_url).send().await {
+            if response.status().is_success() {
+                return Ok(());
+            }
+        }
+        tokio::time::sleep(READY_POLL_INTERVAL).await;
+    }
+
+    Err(format!(
+        "Backend did not become ready at {health_url} within {:?}",
+        READY_TIMEOUT
+    ))
+}
+
+#[tauri::command]
+pub async fn start_backend(
+    supervisor: State<'_, BackendSupervisor>,
+    config: State<'_, AppConfig>,
+) -> Result<(), String> {
+    let child = spawn_backend(&config)?;
+    supervisor.replace(child);
+    wait_until_ready(&config).await
+}
+
+#[tauri::command]
+pub async fn stop_backend(supervisor: State<'_, BackendSupervisor>) -> Result<(), String> {
+    supervisor.kill();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn restart_backend(
+    supervisor: State<'_, BackendSupervisor>,
+    config: State<'_, AppConfig>,
+) -> Result<(), String> {
+    supervisor.kill();
+    let child = spawn_backend(&config)?;
+    supervisor.replace(child);
+    wait_until_ready(&config).await
+}
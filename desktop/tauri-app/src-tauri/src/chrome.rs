@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+/// Finds an installed Chrome/Chromium binary. `BREBOT_CHROME` always wins so
+/// a non-standard install can be pointed at explicitly; otherwise this falls
+/// back through `PATH`, the Windows registry, and a handful of well-known
+/// install directories, in that order of how likely each is to be stale.
+pub fn locate_chrome() -> Option<PathBuf> {
+    locate_chrome_with_override(std::env::var("BREBOT_CHROME").ok())
+}
+
+/// Same as [`locate_chrome`], but takes the `BREBOT_CHROME` value directly
+/// instead of reading it from the environment, so callers (namely tests) can
+/// exercise the override logic without touching shared process-global state.
+fn locate_chrome_with_override(env_override: Option<String>) -> Option<PathBuf> {
+    if let Some(path) = env_override {
+        let path = PathBuf::from(path);
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    for name in candidate_names() {
+        if let Ok(path) = which::which(name) {
+            return Some(path);
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    if let Some(path) = locate_chrome_via_registry() {
+        return Some(path);
+    }
+
+    candidate_paths().into_iter().find(|p| p.exists())
+}
+
+fn candidate_names() -> &'static [&'static str] {
+    if cfg!(target_os = "windows") {
+        &["chrome.exe", "chromium.exe"]
+    } else {
+        &["google-chrome", "chromium", "chromium-browser", "chrome"]
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn locate_chrome_via_registry() -> Option<PathBuf> {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    let key = hklm
+        .open_subkey(r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\chrome.exe")
+        .ok()?;
+    let path: String = key.get_value("").ok()?;
+    let path = PathBuf::from(path);
+    path.exists().then_some(path)
+}
+
+fn candidate_paths() -> Vec<PathBuf> {
+    if cfg!(target_os = "windows") {
+        vec![
+            PathBuf::from(r"C:\Program Files\Google\Chrome\Application\chrome.exe"),
+            PathBuf::from(r"C:\Program Files (x86)\Google\Chrome\Application\chrome.exe"),
+            PathBuf::from(r"C:\Program Files\Chromium\Application\chrome.exe"),
+        ]
+    } else if cfg!(target_os = "macos") {
+        vec![
+            PathBuf::from("/Applications/Google Chrome.app/Contents/MacOS/Google Chrome"),
+            PathBuf::from("/Applications/Chromium.app/Contents/MacOS/Chromium"),
+        ]
+    } else {
+        vec![
+            PathBuf::from("/usr/bin/google-chrome"),
+            PathBuf::from("/usr/bin/chromium-browser"),
+            PathBuf::from("/usr/bin/chromium"),
+            PathBuf::from("/snap/bin/chromium"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_override_wins_over_everything_else() {
+        let fake_chrome = std::env::temp_dir().join("brebot-test-chrome-override");
+        std::fs::write(&fake_chrome, b"").unwrap();
+
+        let found = locate_chrome_with_override(Some(
+            fake_chrome.to_string_lossy().into_owned(),
+        ));
+
+        let _ = std::fs::remove_file(&fake_chrome);
+
+        assert_eq!(found, Some(fake_chrome));
+    }
+
+    #[test]
+    fn env_override_is_ignored_when_path_does_not_exist() {
+        let found = locate_chrome_with_override(Some(
+            "/nonexistent/brebot-test-chrome".to_string(),
+        ));
+
+        assert_ne!(found, Some(PathBuf::from("/nonexistent/brebot-test-chrome")));
+    }
+
+    #[test]
+    fn candidate_paths_is_nonempty_for_every_os() {
+        assert!(!candidate_paths().is_empty());
+    }
+}
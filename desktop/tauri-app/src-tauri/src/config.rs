@@ -0,0 +1,128 @@
+use clap::Parser;
+
+/// Runtime flags for driving the desktop shell like a server launcher —
+/// useful for headless/dev deployments where Chrome, Docker, and the
+/// backend port shouldn't be assumed.
+#[derive(Parser, Debug, Clone)]
+#[command(name = "brebot", about = "Brebot desktop shell")]
+pub struct Cli {
+    /// Port the Python backend listens on.
+    #[arg(long, default_value_t = 8000)]
+    pub backend_port: u16,
+
+    /// Host and port the backend is reachable at, as `host:port` — overrides
+    /// `--backend-port` and the default `127.0.0.1` host. Useful when the
+    /// backend runs on a different machine/container than the desktop shell.
+    #[arg(long)]
+    pub bind: Option<String>,
+
+    /// Skip auto-opening the PWA in a browser.
+    #[arg(long)]
+    pub no_browser: bool,
+
+    /// Skip starting Docker-backed services (chromadb, redis).
+    #[arg(long)]
+    pub no_services: bool,
+}
+
+/// Resolved configuration shared with commands via Tauri managed state, so
+/// the backend host/port scattered across commands come from one place and
+/// dev/prod deployments on alternate hosts or ports work without
+/// recompiling.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub backend_host: String,
+    pub backend_port: u16,
+    pub no_browser: bool,
+    pub no_services: bool,
+}
+
+impl From<Cli> for AppConfig {
+    fn from(cli: Cli) -> Self {
+        let (backend_host, backend_port) = match cli.bind {
+            Some(bind) => parse_bind(&bind).unwrap_or((bind, cli.backend_port)),
+            None => ("127.0.0.1".to_string(), cli.backend_port),
+        };
+
+        Self {
+            backend_host,
+            backend_port,
+            no_browser: cli.no_browser,
+            no_services: cli.no_services,
+        }
+    }
+}
+
+/// Splits a `host:port` string on its last `:`, so IPv6 hosts like
+/// `[::1]:8000` still resolve a trailing numeric port. Returns `None` if
+/// there's no `:port` suffix or it isn't a valid port number, in which case
+/// the caller falls back to treating the whole string as just the host.
+fn parse_bind(bind: &str) -> Option<(String, u16)> {
+    let (host, port) = bind.rsplit_once(':')?;
+    let port: u16 = port.parse().ok()?;
+    Some((host.to_string(), port))
+}
+
+impl AppConfig {
+    pub fn backend_url(&self) -> String {
+        format!("http://{}:{}", self.backend_host, self.backend_port)
+    }
+
+    pub fn health_url(&self) -> String {
+        format!("{}/api/health", self.backend_url())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(backend_port: u16) -> AppConfig {
+        AppConfig {
+            backend_host: "127.0.0.1".to_string(),
+            backend_port,
+            no_browser: false,
+            no_services: false,
+        }
+    }
+
+    #[test]
+    fn backend_url_uses_the_configured_port() {
+        assert_eq!(config(9001).backend_url(), "http://127.0.0.1:9001");
+    }
+
+    #[test]
+    fn health_url_appends_api_health_to_the_backend_url() {
+        assert_eq!(
+            config(8000).health_url(),
+            "http://127.0.0.1:8000/api/health"
+        );
+    }
+
+    #[test]
+    fn bind_overrides_host_and_port() {
+        let config = AppConfig::from(Cli {
+            backend_port: 8000,
+            bind: Some("0.0.0.0:9090".to_string()),
+            no_browser: false,
+            no_services: false,
+        });
+
+        assert_eq!(config.backend_host, "0.0.0.0");
+        assert_eq!(config.backend_port, 9090);
+        assert_eq!(config.backend_url(), "http://0.0.0.0:9090");
+    }
+
+    #[test]
+    fn bind_without_a_port_falls_back_to_backend_port() {
+        let config = AppConfig::from(Cli {
+            backend_port: 8000,
+            bind: Some("example.internal".to_string()),
+            no_browser: false,
+            no_services: false,
+        });
+
+        assert_eq!(config.backend_host, "example.internal");
+        assert_eq!(config.backend_port, 8000);
+    }
+}
@@ -1,127 +1,115 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::path::Path;
+mod backend;
+mod chrome;
+mod config;
+mod services;
+mod uri_scheme;
+
 use std::process::Command;
-use tauri::{AppHandle, Manager};
 
-fn repo_root() -> Result<std::path::PathBuf, String> {
-    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
-    std::fs::canonicalize(root).map_err(|e| e.to_string())
-}
+use clap::Parser;
+use tauri::api::shell::Program;
+use tauri::{AppHandle, Manager, RunEvent, State};
+
+use backend::{restart_backend, start_backend, stop_backend, BackendSupervisor};
+use chrome::locate_chrome;
+use config::{AppConfig, Cli};
+use services::{services_status, start_services, stop_services};
 
 #[tauri::command]
-async fn open_backend(app: AppHandle) -> Result<(), String> {
-    let url = "http://localhost:8000";
-    tauri::api::shell::open(&app.shell_scope(), url, None).map_err(|e| e.to_string())
+async fn open_backend(app: AppHandle, config: State<'_, AppConfig>) -> Result<(), String> {
+    if config.no_browser {
+        return Ok(());
+    }
+    tauri::api::shell::open(&app.shell_scope(), config.backend_url(), None)
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn open_pwa_in_chrome() -> Result<(), String> {
-    let url = "http://127.0.0.1:8000";
-    
-    // Try to open in Chrome specifically
-    let chrome_paths = [
-        "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
-        "/Applications/Chromium.app/Contents/MacOS/Chromium",
-        "/usr/bin/google-chrome",
-        "/usr/bin/chromium-browser"
-    ];
-    
-    for chrome_path in &chrome_paths {
-        if std::path::Path::new(chrome_path).exists() {
-            let mut cmd = Command::new(chrome_path);
-            cmd.arg("--new-window")
-               .arg("--app")
-               .arg("--user-data-dir=/tmp/brebot-chrome")
-               .arg("--no-first-run")
-               .arg("--no-default-browser-check")
-               .arg(url);
-            
-            match cmd.spawn() {
-                Ok(_) => return Ok(()),
-                Err(e) => println!("Failed to launch Chrome at {}: {}", chrome_path, e),
-            }
-        }
+async fn open_pwa_in_chrome(config: State<'_, AppConfig>) -> Result<(), String> {
+    if config.no_browser {
+        return Ok(());
     }
-    
-    // Fallback to default browser
-    let mut cmd = Command::new("open");
-    cmd.arg(url);
-    cmd.spawn()
-        .map_err(|e| format!("Failed to open browser: {e}"))?;
+    let url = config.backend_url();
+
+    let Some(chrome_path) = locate_chrome() else {
+        return Err(
+            "Could not find a Chrome/Chromium install. Checked BREBOT_CHROME, PATH, \
+             the Windows registry, and the usual per-OS install locations. Set \
+             BREBOT_CHROME to the binary path to override."
+                .to_string(),
+        );
+    };
+
+    Command::new(&chrome_path)
+        .arg("--new-window")
+        .arg("--app")
+        .arg("--user-data-dir=/tmp/brebot-chrome")
+        .arg("--no-first-run")
+        .arg("--no-default-browser-check")
+        .arg(url)
+        .spawn()
+        .map_err(|e| format!("Failed to launch Chrome at {:?}: {e}", chrome_path))?;
     Ok(())
 }
 
+/// Opens the PWA through Tauri's scope-checked shell API, so the frontend
+/// can offer a browser picker that's portable and sandboxed on every OS.
+/// `browser` is one of `chrome`, `chromium`, `firefox`, `safari`, `open`,
+/// or `xdg-open`; `None` means `chrome`, and any other name uses the OS
+/// default opener. Chrome keeps its dedicated app-mode window (via
+/// `open_pwa_in_chrome`) where the platform has a Chrome install; everything
+/// else goes through `shell::open`.
+#[tauri::command]
+async fn open_pwa(
+    app: AppHandle,
+    browser: Option<String>,
+    config: State<'_, AppConfig>,
+) -> Result<(), String> {
+    if config.no_browser {
+        return Ok(());
+    }
+    let url = config.backend_url();
+    let browser = browser.unwrap_or_else(|| "chrome".to_string());
+
+    if browser.eq_ignore_ascii_case("chrome") && locate_chrome().is_some() {
+        return open_pwa_in_chrome(config).await;
+    }
+
+    let with = match browser.to_lowercase().as_str() {
+        "chrome" => Some(Program::Chrome),
+        "chromium" => Some(Program::Chromium),
+        "firefox" => Some(Program::Firefox),
+        "safari" => Some(Program::Safari),
+        "open" => Some(Program::Open),
+        "xdg-open" => Some(Program::XdgOpen),
+        _ => None,
+    };
+
+    tauri::api::shell::open(&app.shell_scope(), url, with).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
-async fn navigate_to_dashboard(app: AppHandle) -> Result<(), String> {
-    let url = "http://127.0.0.1:8000";
-    
+async fn navigate_to_dashboard(app: AppHandle, config: State<'_, AppConfig>) -> Result<(), String> {
+    let url = config.backend_url();
+
     // Get the main window
     if let Some(window) = app.get_window("main") {
-        window.eval(&format!("window.location.href = '{}';", url))
+        window
+            .eval(&format!("window.location.href = '{}';", url))
             .map_err(|e| format!("Failed to navigate: {e}"))?;
     } else {
         return Err("Main window not found".to_string());
     }
-    
-    Ok(())
-}
-
-#[tauri::command]
-async fn start_backend() -> Result<(), String> {
-    let root = repo_root()?;
-    println!("Repo root: {:?}", root);
-
-    let venv_python = root.join("venv/bin/python3");
-    println!("Venv python path: {:?}", venv_python);
-    println!("Venv python exists: {}", venv_python.exists());
-    
-    let interpreter = if venv_python.exists() {
-        venv_python
-    } else {
-        Path::new("python3").to_path_buf()
-    };
-    println!("Using interpreter: {:?}", interpreter);
-
-    let mut cmd = Command::new(&interpreter);
-    cmd.current_dir(&root)
-        .args(["src/main.py", "web"]);
-    
-    println!("Running command: {:?}", cmd);
-    
-    cmd.spawn()
-        .map_err(|e| format!("Failed to launch backend: {e}"))?;
-    Ok(())
-}
 
-#[tauri::command]
-async fn start_services() -> Result<(), String> {
-    let root = repo_root()?;
-    println!("Starting services from: {:?}", root);
-    
-    let mut cmd = Command::new("docker");
-    cmd.current_dir(&root)
-        .args([
-            "compose",
-            "-f",
-            "docker/docker-compose.yml",
-            "up",
-            "-d",
-            "chromadb",
-            "redis",
-        ]);
-    
-    println!("Running Docker command: {:?}", cmd);
-    
-    cmd.spawn()
-        .map_err(|e| format!("Failed to start Docker services: {e}"))?;
     Ok(())
 }
 
 #[tauri::command]
-async fn check_backend_health() -> Result<String, String> {
+async fn check_backend_health(config: State<'_, AppConfig>) -> Result<String, String> {
     let client = reqwest::Client::new();
-    match client.get("http://127.0.0.1:8000/api/health").send().await {
+    match client.get(config.health_url()).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 match response.text().await {
@@ -137,8 +125,40 @@ async fn check_backend_health() -> Result<String, String> {
 }
 
 fn main() {
+    let config = AppConfig::from(Cli::parse());
+
+    if config.no_browser || config.no_services {
+        println!(
+            "Running headless (backend={}:{}, no_browser={}, no_services={})",
+            config.backend_host, config.backend_port, config.no_browser, config.no_services
+        );
+    }
+
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![open_backend, open_pwa_in_chrome, navigate_to_dashboard, start_backend, start_services, check_backend_health])
-        .run(tauri::generate_context!())
-        .expect("error while running Brebot Desktop");
+        .manage(BackendSupervisor::default())
+        .manage(config)
+        .register_uri_scheme_protocol("brebot", uri_scheme::handle)
+        .invoke_handler(tauri::generate_handler![
+            open_backend,
+            open_pwa_in_chrome,
+            open_pwa,
+            navigate_to_dashboard,
+            start_backend,
+            stop_backend,
+            restart_backend,
+            start_services,
+            stop_services,
+            services_status,
+            check_backend_health
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while building Brebot Desktop")
+        .run(|app_handle, event| {
+            // Desktop Tauri calls `std::process::exit` when the last window
+            // closes, which skips `Drop` — reap the backend child here
+            // instead of relying on a destructor that will never run.
+            if let RunEvent::Exit = event {
+                app_handle.state::<BackendSupervisor>().kill();
+            }
+        });
 }
@@ -0,0 +1,202 @@
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::config::AppConfig;
+
+const COMPOSE_FILE: &str = "docker/docker-compose.yml";
+const CHROMADB_PORT: u16 = 8001;
+const REDIS_PORT: u16 = 6379;
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Service")]
+    pub service: String,
+    #[serde(rename = "State")]
+    pub state: String,
+    #[serde(rename = "Health", default)]
+    pub health: String,
+}
+
+fn repo_root() -> Result<PathBuf, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    std::fs::canonicalize(root).map_err(|e| e.to_string())
+}
+
+fn ensure_docker_ready(root: &Path) -> Result<PathBuf, String> {
+    Command::new("docker")
+        .arg("--version")
+        .output()
+        .map_err(|_| "Docker is not installed or not on PATH".to_string())?;
+
+    let compose_path = root.join(COMPOSE_FILE);
+    if !compose_path.exists() {
+        return Err(format!(
+            "Compose file not found at {:?} — is this running from the repo root?",
+            compose_path
+        ));
+    }
+
+    Ok(compose_path)
+}
+
+fn compose_command(compose_path: &Path) -> Command {
+    let mut cmd = Command::new("docker");
+    cmd.args(["compose", "-f"]).arg(compose_path);
+    cmd
+}
+
+fn port_reachable(port: u16) -> bool {
+    TcpStream::connect(("127.0.0.1", port)).is_ok()
+}
+
+async fn wait_until_reachable(ports: &[u16]) -> Result<(), String> {
+    let deadline = Instant::now() + READY_TIMEOUT;
+
+    while Instant::now() < deadline {
+        if ports.iter().all(|&port| port_reachable(port)) {
+            return Ok(());
+        }
+        tokio::time::sleep(READY_POLL_INTERVAL).await;
+    }
+
+    Err(format!(
+        "Services did not become reachable on ports {:?} within {:?}",
+        ports, READY_TIMEOUT
+    ))
+}
+
+#[tauri::command]
+pub async fn start_services(
+    wait_for_health: Option<bool>,
+    config: State<'_, AppConfig>,
+) -> Result<(), String> {
+    if config.no_services {
+        return Ok(());
+    }
+
+    let root = repo_root()?;
+    let compose_path = ensure_docker_ready(&root)?;
+
+    let output = compose_command(&compose_path)
+        .current_dir(&root)
+        .args(["up", "-d", "chromadb", "redis"])
+        .output()
+        .map_err(|e| format!("Failed to start Docker services: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "docker compose up exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    if wait_for_health.unwrap_or(false) {
+        wait_until_reachable(&[CHROMADB_PORT, REDIS_PORT]).await?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn stop_services() -> Result<(), String> {
+    let root = repo_root()?;
+    let compose_path = ensure_docker_ready(&root)?;
+
+    let output = compose_command(&compose_path)
+        .current_dir(&root)
+        .args(["stop", "chromadb", "redis"])
+        .output()
+        .map_err(|e| format!("Failed to stop Docker services: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "docker compose stop exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn services_status() -> Result<Vec<ServiceStatus>, String> {
+    let root = repo_root()?;
+    let compose_path = ensure_docker_ready(&root)?;
+
+    let output = compose_command(&compose_path)
+        .current_dir(&root)
+        .args(["ps", "--format", "json"])
+        .output()
+        .map_err(|e| format!("Failed to query Docker service status: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "docker compose ps exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    parse_service_status_lines(&output.stdout)
+}
+
+/// `docker compose ps --format json` prints one JSON object per line (not a
+/// JSON array), so each non-empty line is decoded independently.
+fn parse_service_status_lines(stdout: &[u8]) -> Result<Vec<ServiceStatus>, String> {
+    String::from_utf8_lossy(stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| format!("Failed to parse service status: {e}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_one_json_object_per_line() {
+        let stdout = concat!(
+            r#"{"Name":"brebot-chromadb-1","Service":"chromadb","State":"running","Health":"healthy"}"#,
+            "\n",
+            r#"{"Name":"brebot-redis-1","Service":"redis","State":"running","Health":""}"#,
+            "\n"
+        );
+
+        let statuses = parse_service_status_lines(stdout.as_bytes()).unwrap();
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].service, "chromadb");
+        assert_eq!(statuses[0].health, "healthy");
+        assert_eq!(statuses[1].service, "redis");
+        assert_eq!(statuses[1].state, "running");
+    }
+
+    #[test]
+    fn ignores_blank_lines_between_entries() {
+        let stdout = "\n{\"Name\":\"n\",\"Service\":\"s\",\"State\":\"running\"}\n\n";
+
+        let statuses = parse_service_status_lines(stdout.as_bytes()).unwrap();
+
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].health, "");
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        assert!(parse_service_status_lines(b"not json").is_err());
+    }
+}
@@ -0,0 +1,46 @@
+use tauri::http::{Request, Response, ResponseBuilder};
+use tauri::{AppHandle, Manager};
+
+use crate::config::AppConfig;
+
+/// Handler for the custom `brebot://` URI scheme, registered via
+/// `register_uri_scheme_protocol`. Strips the `brebot://localhost/` prefix
+/// off the request and re-fetches that path from the backend, relaying its
+/// status and `Content-Type` back verbatim. Runs synchronously (the webview
+/// protocol callback isn't async), so the actual `reqwest` call is driven
+/// through `block_on`.
+pub fn handle(app: &AppHandle, request: &Request) -> Result<Response, Box<dyn std::error::Error>> {
+    let config = app.state::<AppConfig>();
+    let path = request
+        .uri()
+        .strip_prefix("brebot://localhost/")
+        .or_else(|| request.uri().strip_prefix("brebot://localhost"))
+        .unwrap_or("");
+    let target_url = format!("{}/{path}", config.backend_url());
+
+    let result = tauri::async_runtime::block_on(async {
+        let response = reqwest::get(&target_url).await?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let bytes = response.bytes().await?;
+        Ok::<_, reqwest::Error>((status, content_type, bytes.to_vec()))
+    });
+
+    match result {
+        Ok((status, content_type, body)) => ResponseBuilder::new()
+            .status(status)
+            .header("Content-Type", content_type)
+            .body(body)
+            .map_err(Into::into),
+        Err(e) => ResponseBuilder::new()
+            .status(404)
+            .header("Content-Type", "text/plain")
+            .body(format!("Backend unavailable at {target_url}: {e}").into_bytes())
+            .map_err(Into::into),
+    }
+}